@@ -2,16 +2,174 @@
 pub mod query_builder {
     use std::collections::HashMap;
 
+    /// Describes how a particular SQL flavor wants identifiers quoted and
+    /// `LIMIT`/`OFFSET` rendered, so the same builder call can target
+    /// Postgres, MySQL, or SQLite. Raw condition/value fragments (as passed
+    /// to `filter()`, `set()`, `filter_in()`, etc.) are the caller's
+    /// responsibility to placeholder in whatever style their driver expects
+    /// (see each dialect's doc comment below) — this builder never generates
+    /// bound-parameter placeholders itself.
+    pub trait Dialect {
+        /// Wrap a table name, column name, or plain `Select::fields()` entry
+        /// so reserved words and mixed-case identifiers survive unmangled.
+        /// `fields()` entries containing `(` (aggregate/expression fields
+        /// like `COUNT(*) AS n`) bypass this and are emitted as raw
+        /// fragments instead, since those can't be safely identifier-quoted;
+        /// `group_by()`/`having()` are raw fragments unconditionally for the
+        /// same reason.
+        fn quote_identifier(&self, identifier: &str) -> String;
+
+        /// Render the `LIMIT`/`OFFSET` fragment (including the keywords).
+        /// `limit` of `None` means "no cap, just skip `offset` rows" —
+        /// dialects without a bare `OFFSET` must still express that.
+        fn limit_offset(&self, limit: Option<usize>, offset: usize) -> String;
+
+        /// Render a boolean literal
+        fn boolean_literal(&self, value: bool) -> String;
+    }
+
+    /// A conservative default dialect: no identifier quoting, `$n` placeholders,
+    /// and ANSI-style `LIMIT ... OFFSET ...`
+    pub struct Generic;
+
+    /// PostgreSQL dialect: double-quoted identifiers, `$n` placeholders
+    pub struct Postgres;
+
+    /// MySQL dialect: backtick-quoted identifiers, `?` placeholders, and
+    /// `LIMIT offset, count` syntax
+    pub struct MySql;
+
+    /// SQLite dialect: double-quoted identifiers, `?n` placeholders
+    pub struct Sqlite;
+
+    impl Dialect for Generic {
+        fn quote_identifier(&self, identifier: &str) -> String {
+            identifier.to_string()
+        }
+
+        fn limit_offset(&self, limit: Option<usize>, offset: usize) -> String {
+            match limit {
+                Some(limit) if offset != 0 => format!("LIMIT {} OFFSET {}", limit, offset),
+                Some(limit) => format!("LIMIT {}", limit),
+                None => format!("OFFSET {}", offset),
+            }
+        }
+
+        fn boolean_literal(&self, value: bool) -> String {
+            if value { String::from("TRUE") } else { String::from("FALSE") }
+        }
+    }
+
+    impl Dialect for Postgres {
+        fn quote_identifier(&self, identifier: &str) -> String {
+            format!("\"{}\"", identifier.replace("\"", "\"\""))
+        }
+
+        fn limit_offset(&self, limit: Option<usize>, offset: usize) -> String {
+            match limit {
+                Some(limit) if offset != 0 => format!("LIMIT {} OFFSET {}", limit, offset),
+                Some(limit) => format!("LIMIT {}", limit),
+                None => format!("OFFSET {}", offset),
+            }
+        }
+
+        fn boolean_literal(&self, value: bool) -> String {
+            if value { String::from("TRUE") } else { String::from("FALSE") }
+        }
+    }
+
+    impl Dialect for MySql {
+        fn quote_identifier(&self, identifier: &str) -> String {
+            format!("`{}`", identifier.replace("`", "``"))
+        }
+
+        fn limit_offset(&self, limit: Option<usize>, offset: usize) -> String {
+            match limit {
+                Some(limit) if offset != 0 => format!("LIMIT {}, {}", offset, limit),
+                Some(limit) => format!("LIMIT {}", limit),
+                // MySQL has no bare OFFSET syntax; the documented workaround
+                // for "skip N, no cap" is a LIMIT row count of the maximum
+                // possible value.
+                None => format!("LIMIT {}, {}", offset, u64::MAX),
+            }
+        }
+
+        fn boolean_literal(&self, value: bool) -> String {
+            if value { String::from("1") } else { String::from("0") }
+        }
+    }
+
+    impl Dialect for Sqlite {
+        fn quote_identifier(&self, identifier: &str) -> String {
+            format!("\"{}\"", identifier.replace("\"", "\"\""))
+        }
+
+        fn limit_offset(&self, limit: Option<usize>, offset: usize) -> String {
+            match limit {
+                Some(limit) if offset != 0 => format!("LIMIT {} OFFSET {}", limit, offset),
+                Some(limit) => format!("LIMIT {}", limit),
+                None => format!("OFFSET {}", offset),
+            }
+        }
+
+        fn boolean_literal(&self, value: bool) -> String {
+            if value { String::from("1") } else { String::from("0") }
+        }
+    }
+
+    /// Construct the default dialect used when a builder isn't given one
+    fn default_dialect() -> Box<dyn Dialect> {
+        Box::new(Generic)
+    }
+
+    /// Combine a slice of `&str`s, with `sep` between each value. Used for
+    /// field/expression lists that may contain arbitrary SQL (e.g. aggregate
+    /// expressions), which can't be safely identifier-quoted.
+    fn join_strs(v: &Vec<&str>, sep: &str) -> String {
+        let mut s = String::new();
+        let last_i = v.len() - 1;
+        for (i, val) in v.iter().enumerate() {
+            s += val;
+            if i != last_i {
+                s += sep;
+            }
+        }
+        s
+    }
+
+    /// Render a `SELECT` field list, identifier-quoting each plain column
+    /// name per-dialect but passing entries containing `(` (aggregate or
+    /// other expressions, e.g. `COUNT(*) AS n`) through raw, since those
+    /// can't be safely identifier-quoted
+    fn render_fields(fields: &Vec<&str>, dialect: &dyn Dialect) -> String {
+        let mut s = String::new();
+        let last_i = fields.len() - 1;
+        for (i, field) in fields.iter().enumerate() {
+            if field.contains('(') {
+                s += field;
+            } else {
+                s += dialect.quote_identifier(field).as_str();
+            }
+            if i != last_i {
+                s += ", ";
+            }
+        }
+        s
+    }
+
     /// `DELETE`
     pub struct Delete<'a> {
         table: &'a str,
-        conditions: Option<Vec<&'a str>>,
+        conditions: Option<Vec<ConditionNode>>,
+        dialect: Box<dyn Dialect>,
     }
 
     /// `INSERT`
     pub struct Insert<'a> {
         table: &'a str,
         values: HashMap<&'a str, &'a str>,
+        typed_values: HashMap<&'a str, Value>,
+        dialect: Box<dyn Dialect>,
     }
 
     /// `SELECT`
@@ -20,31 +178,140 @@ pub mod query_builder {
         aliases: Option<HashMap<&'a str, &'a str>>,
         fields: Option<Vec<&'a str>>,
         order: Option<Vec<(&'a str, Order)>>,
-        conditions: Option<Vec<&'a str>>,
-        limit: usize,
+        conditions: Option<Vec<ConditionNode>>,
+        joins: Option<Vec<JoinClause<'a>>>,
+        group_by: Option<Vec<&'a str>>,
+        having: Option<Vec<&'a str>>,
+        limit: Option<usize>,
         offset: usize,
+        dialect: Box<dyn Dialect>,
     }
 
     /// `UPDATE`
     pub struct Update<'a> {
         table: &'a str,
         values: HashMap<&'a str, &'a str>,
-        conditions: Option<Vec<&'a str>>,
+        typed_values: HashMap<&'a str, Value>,
+        conditions: Option<Vec<ConditionNode>>,
+        dialect: Box<dyn Dialect>,
     }
 
     /// The direction of an `ORDER` clause's expression
     pub enum Order { Asc, Desc }
 
-    /// Combine a vector of `String`s, with the `sep` `str` between each value
-    fn join(v: &Vec<&str>, sep: &str) -> String {
+    /// The kind of `JOIN` to perform between two tables
+    pub enum JoinType { Inner, Left, Right, Outer, Cross }
+
+    impl JoinType {
+        fn as_sql(&self) -> &'static str {
+            match *self {
+                JoinType::Inner => "INNER JOIN",
+                JoinType::Left => "LEFT JOIN",
+                JoinType::Right => "RIGHT JOIN",
+                JoinType::Outer => "FULL OUTER JOIN",
+                JoinType::Cross => "CROSS JOIN",
+            }
+        }
+    }
+
+    /// A single `JOIN` clause: `<join_type> <table> ON <on_left> = <on_right>`
+    struct JoinClause<'a> {
+        join_type: JoinType,
+        table: &'a str,
+        on_left: &'a str,
+        on_right: &'a str,
+    }
+
+    /// A typed literal value, rendered safely (quoted/escaped) by `build()`
+    /// instead of being spliced into the query verbatim
+    pub enum Value {
+        Varchar(String),
+        Text(String),
+        Int(i64),
+        Float(f64),
+        Bool(bool),
+        Null,
+    }
+
+    impl Value {
+        /// Render this value as a SQL literal for the given `dialect`
+        fn render(&self, dialect: &dyn Dialect) -> String {
+            match *self {
+                Value::Varchar(ref s) | Value::Text(ref s) => {
+                    format!("'{}'", s.replace("'", "''"))
+                },
+                Value::Int(n) => n.to_string(),
+                Value::Float(n) => n.to_string(),
+                Value::Bool(b) => dialect.boolean_literal(b),
+                Value::Null => String::from("NULL"),
+            }
+        }
+    }
+
+    /// A single node in a `WHERE` clause's condition list, letting
+    /// conditions be AND-ed, OR-ed, and parenthetically grouped instead of
+    /// only ever ANDed together
+    enum ConditionNode {
+        And(String),
+        Or(String),
+        GroupStart,
+        GroupEnd,
+    }
+
+    /// Where to place the `%` wildcard around a `LIKE` term
+    pub enum LikeWildcard { Before, After, Both }
+
+    /// Escape any existing `%`/`_` in `term` using `\` as the escape
+    /// character, then wrap it with `%` per `wildcard`. Callers must pair
+    /// this with an explicit `ESCAPE '\'` clause, since not every dialect
+    /// (e.g. SQLite) treats `\` as the `LIKE` escape character by default.
+    fn like_pattern(term: &str, wildcard: &LikeWildcard) -> String {
+        let escaped = term.replace("\\", "\\\\").replace("%", "\\%").replace("_", "\\_");
+
+        match *wildcard {
+            LikeWildcard::Before => format!("%{}", escaped),
+            LikeWildcard::After => format!("{}%", escaped),
+            LikeWildcard::Both => format!("%{}%", escaped),
+        }
+    }
+
+    /// Render a sequence of `ConditionNode`s into a `WHERE`-clause fragment,
+    /// omitting the leading conjunction right after the clause starts or an
+    /// open paren
+    fn render_conditions(conditions: &Vec<ConditionNode>) -> String {
         let mut s = String::new();
-        let last_i = v.len() - 1;
-        for (i, val) in v.iter().enumerate() {
-            s += val;
-            if i != last_i {
-                s += sep;
+        let mut need_conjunction = false;
+
+        for node in conditions.iter() {
+            match node {
+                &ConditionNode::GroupStart => {
+                    if need_conjunction {
+                        s += " AND ";
+                    }
+                    s += "(";
+                    need_conjunction = false;
+                },
+                &ConditionNode::GroupEnd => {
+                    s += ")";
+                    need_conjunction = true;
+                },
+                &ConditionNode::And(ref expr) => {
+                    if need_conjunction {
+                        s += " AND ";
+                    }
+                    s += expr.as_str();
+                    need_conjunction = true;
+                },
+                &ConditionNode::Or(ref expr) => {
+                    if need_conjunction {
+                        s += " OR ";
+                    }
+                    s += expr.as_str();
+                    need_conjunction = true;
+                },
             }
         }
+
         s
     }
 
@@ -54,36 +321,113 @@ pub mod query_builder {
             let query_builder = Delete {
                 table: table,
                 conditions: None,
+                dialect: default_dialect(),
             };
 
             query_builder
         }
 
-        /// Filter result set based on conditions (`WHERE` clause)
+        /// Set the SQL dialect used to render this query
+        pub fn dialect(&mut self, dialect: Box<dyn Dialect>) -> &mut Self {
+            self.dialect = dialect;
+            self
+        }
+
+        /// Filter result set based on conditions, AND-ed with any prior
+        /// conditions (`WHERE` clause)
         pub fn filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::And(expr.to_string()));
+            self
+        }
+
+        /// Filter result set based on conditions, OR-ed with any prior
+        /// conditions (`WHERE` clause)
+        pub fn or_filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::Or(expr.to_string()));
+            self
+        }
+
+        /// Filter result set to rows whose column is one of `values`
+        /// (`IN` clause), AND-ed with any prior conditions. An empty
+        /// `values` list renders a guaranteed-false predicate so the
+        /// query stays valid. `values` are raw fragments dropped into the
+        /// `IN (...)` list as-is, the same convention as `filter()`/`set()`
+        /// — callers are responsible for quoting/placeholdering them in
+        /// whatever style their driver expects.
+        pub fn filter_in(&mut self, column: &'a str, values: &[&str]) -> &mut Self {
+            let expr = if values.is_empty() {
+                String::from("1 = 0")
+            } else {
+                format!("{} IN ({})", column, values.join(", "))
+            };
+            self.push_condition(ConditionNode::And(expr));
+            self
+        }
+
+        /// Filter result set to rows whose column matches a `LIKE` pattern,
+        /// AND-ed with any prior conditions. Emits an explicit `ESCAPE '\'`
+        /// clause so the escaping in `wildcard`'s pattern holds across
+        /// dialects (SQLite has no default `LIKE` escape character)
+        pub fn like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Filter result set to rows whose column does not match a `LIKE`
+        /// pattern, AND-ed with any prior conditions. Emits an explicit
+        /// `ESCAPE '\'` clause so the escaping in `wildcard`'s pattern
+        /// holds across dialects (SQLite has no default `LIKE` escape
+        /// character)
+        pub fn not_like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} NOT LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Open a parenthetical group of conditions
+        pub fn open_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupStart);
+            self
+        }
+
+        /// Close a parenthetical group of conditions
+        pub fn close_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupEnd);
+            self
+        }
+
+        /// Add a parenthetically grouped set of conditions, built by `f`
+        pub fn filter_group<F>(&mut self, f: F) -> &mut Self
+            where F: FnOnce(&mut Self) -> &mut Self
+        {
+            self.open_group();
+            f(self);
+            self.close_group();
+            self
+        }
+
+        fn push_condition(&mut self, node: ConditionNode) {
             if self.conditions.is_none() {
                 self.conditions = Some(Vec::new());
             }
 
             match self.conditions {
                 Some(ref mut current_conditions) => {
-                    current_conditions.push(expr);
+                    current_conditions.push(node);
                 },
                 None => unreachable!(),
             }
-
-            self
         }
 
-
         /// Generate SQL query (`String`) from subsequent method calls
         pub fn build(&self) -> String {
             let mut query = String::from("DELETE FROM ");
-            query += self.table;
+            query += self.dialect.quote_identifier(self.table).as_str();
 
             if let Some(ref conditions) = self.conditions {
                 query += " WHERE ";
-                query += join(conditions, " AND ").as_str();
+                query += render_conditions(conditions).as_str();
             }
 
             query += ";";
@@ -97,34 +441,54 @@ pub mod query_builder {
             let query_builder = Insert {
                 table: table,
                 values: HashMap::new(),
+                typed_values: HashMap::new(),
+                dialect: default_dialect(),
             };
 
             query_builder
         }
 
-        /// Set a field value
+        /// Set the SQL dialect used to render this query
+        pub fn dialect(&mut self, dialect: Box<dyn Dialect>) -> &mut Self {
+            self.dialect = dialect;
+            self
+        }
+
+        /// Set a field value as a raw fragment (e.g. a `$1` placeholder).
+        /// The caller is responsible for quoting any literal it passes.
         pub fn set(&mut self, field: &'a str, value: &'a str) -> &mut Self {
             let _ = self.values.insert(field, value);
             self
         }
 
+        /// Set a field to a typed literal `Value`, quoted/escaped safely
+        pub fn set_value(&mut self, field: &'a str, value: Value) -> &mut Self {
+            let _ = self.typed_values.insert(field, value);
+            self
+        }
+
         /// Generate SQL query (`String`) from subsequent method calls
         pub fn build(&self) -> String {
             let mut query = String::from("INSERT INTO ");
-            query += self.table;
+            query += self.dialect.quote_identifier(self.table).as_str();
 
-            let mut columns: Vec<&str> = Vec::new();
-            let mut values: Vec<&str> = Vec::new();
+            let mut columns: Vec<String> = Vec::new();
+            let mut values: Vec<String> = Vec::new();
 
             for (field, value) in self.values.iter() {
-                columns.push(field);
-                values.push(value);
+                columns.push(self.dialect.quote_identifier(field));
+                values.push(value.to_string());
+            }
+
+            for (field, value) in self.typed_values.iter() {
+                columns.push(self.dialect.quote_identifier(field));
+                values.push(value.render(self.dialect.as_ref()));
             }
 
             query += " (";
-            query += join(&columns, ", ").as_str();
+            query += columns.join(", ").as_str();
             query += ") VALUES (";
-            query += join(&values, ", ").as_str();
+            query += values.join(", ").as_str();
             query += ");";
             query
         }
@@ -139,13 +503,23 @@ pub mod query_builder {
                 fields: None,
                 order: None,
                 conditions: None,
-                limit: 0usize,
+                joins: None,
+                group_by: None,
+                having: None,
+                limit: None,
                 offset: 0usize,
+                dialect: default_dialect(),
             };
 
             query_builder
         }
 
+        /// Set the SQL dialect used to render this query
+        pub fn dialect(&mut self, dialect: Box<dyn Dialect>) -> &mut Self {
+            self.dialect = dialect;
+            self
+        }
+
         /// Set a table alias (`AS`)
         pub fn alias(&mut self, table: &'a str, alias: &'a str) -> &mut Self {
             if self.aliases.is_none() {
@@ -162,7 +536,11 @@ pub mod query_builder {
             self
         }
 
-        /// Specify desired table fields in result set
+        /// Specify desired table fields in result set. Plain column names
+        /// are identifier-quoted per-dialect; entries containing `(`
+        /// (aggregate or other expressions, e.g. `COUNT(*) AS n`) are
+        /// passed through as raw fragments instead, since those can't be
+        /// safely identifier-quoted.
         pub fn fields(&mut self, fields: &[&'a str]) -> &mut Self {
             if self.fields.is_none() {
                 self.fields = Some(Vec::new());
@@ -177,23 +555,94 @@ pub mod query_builder {
                 None => unreachable!(),
             }
 
-            self 
+            self
         }
 
-        /// Filter result set based on conditions (`WHERE` clause)
+        /// Filter result set based on conditions, AND-ed with any prior
+        /// conditions (`WHERE` clause)
         pub fn filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::And(expr.to_string()));
+            self
+        }
+
+        /// Filter result set based on conditions, OR-ed with any prior
+        /// conditions (`WHERE` clause)
+        pub fn or_filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::Or(expr.to_string()));
+            self
+        }
+
+        /// Filter result set to rows whose column is one of `values`
+        /// (`IN` clause), AND-ed with any prior conditions. An empty
+        /// `values` list renders a guaranteed-false predicate so the
+        /// query stays valid. `values` are raw fragments dropped into the
+        /// `IN (...)` list as-is, the same convention as `filter()`/`set()`
+        /// — callers are responsible for quoting/placeholdering them in
+        /// whatever style their driver expects.
+        pub fn filter_in(&mut self, column: &'a str, values: &[&str]) -> &mut Self {
+            let expr = if values.is_empty() {
+                String::from("1 = 0")
+            } else {
+                format!("{} IN ({})", column, values.join(", "))
+            };
+            self.push_condition(ConditionNode::And(expr));
+            self
+        }
+
+        /// Filter result set to rows whose column matches a `LIKE` pattern,
+        /// AND-ed with any prior conditions. Emits an explicit `ESCAPE '\'`
+        /// clause so the escaping in `wildcard`'s pattern holds across
+        /// dialects (SQLite has no default `LIKE` escape character)
+        pub fn like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Filter result set to rows whose column does not match a `LIKE`
+        /// pattern, AND-ed with any prior conditions. Emits an explicit
+        /// `ESCAPE '\'` clause so the escaping in `wildcard`'s pattern
+        /// holds across dialects (SQLite has no default `LIKE` escape
+        /// character)
+        pub fn not_like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} NOT LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Open a parenthetical group of conditions
+        pub fn open_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupStart);
+            self
+        }
+
+        /// Close a parenthetical group of conditions
+        pub fn close_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupEnd);
+            self
+        }
+
+        /// Add a parenthetically grouped set of conditions, built by `f`
+        pub fn filter_group<F>(&mut self, f: F) -> &mut Self
+            where F: FnOnce(&mut Self) -> &mut Self
+        {
+            self.open_group();
+            f(self);
+            self.close_group();
+            self
+        }
+
+        fn push_condition(&mut self, node: ConditionNode) {
             if self.conditions.is_none() {
                 self.conditions = Some(Vec::new());
             }
 
             match self.conditions {
                 Some(ref mut current_conditions) => {
-                    current_conditions.push(expr);
+                    current_conditions.push(node);
                 },
                 None => unreachable!(),
             }
-
-            self
         }
 
         /// Order result set based on the value of an expression (`ORDER BY` clause)
@@ -210,22 +659,77 @@ pub mod query_builder {
                 None => unreachable!(),
             }
 
-            self 
+            self
+        }
+
+        /// Group result set rows by the given expressions (`GROUP BY` clause)
+        pub fn group_by(&mut self, fields: &[&'a str]) -> &mut Self {
+            if self.group_by.is_none() {
+                self.group_by = Some(Vec::new());
+            }
+
+            match self.group_by {
+                Some(ref mut current_group_by) => {
+                    for field in fields {
+                        current_group_by.push(field);
+                    }
+                },
+                None => unreachable!(),
+            }
+
+            self
         }
 
-        #[allow(unused_variables)]
-        pub fn inner_join(&mut self, table: &str, on_left: &str, on_right: &str) -> &mut Self {
+        /// Filter grouped result set based on conditions (`HAVING` clause)
+        pub fn having(&mut self, expr: &'a str) -> &mut Self {
+            if self.having.is_none() {
+                self.having = Some(Vec::new());
+            }
+
+            match self.having {
+                Some(ref mut current_having) => {
+                    current_having.push(expr);
+                },
+                None => unreachable!(),
+            }
+
             self
         }
 
-        #[allow(unused_variables)]
-        pub fn left_join(&mut self, table: &str, on_left: &str, on_right: &str) -> &mut Self {
+        /// Join another table into the result set (`JOIN` clause)
+        pub fn join(&mut self, join_type: JoinType, table: &'a str, on_left: &'a str, on_right: &'a str) -> &mut Self {
+            if self.joins.is_none() {
+                self.joins = Some(Vec::new());
+            }
+
+            match self.joins {
+                Some(ref mut current_joins) => {
+                    current_joins.push(JoinClause {
+                        join_type: join_type,
+                        table: table,
+                        on_left: on_left,
+                        on_right: on_right,
+                    });
+                },
+                None => unreachable!(),
+            }
+
             self
         }
 
+        /// Join another table into the result set (`INNER JOIN` clause)
+        pub fn inner_join(&mut self, table: &'a str, on_left: &'a str, on_right: &'a str) -> &mut Self {
+            self.join(JoinType::Inner, table, on_left, on_right)
+        }
+
+        /// Join another table into the result set (`LEFT JOIN` clause)
+        pub fn left_join(&mut self, table: &'a str, on_left: &'a str, on_right: &'a str) -> &mut Self {
+            self.join(JoinType::Left, table, on_left, on_right)
+        }
+
         /// Limit number of rows in result set (`LIMIT`)
         pub fn limit(&mut self, limit: usize) -> &mut Self {
-            self.limit = limit;
+            self.limit = Some(limit);
             self
         }
 
@@ -241,24 +745,55 @@ pub mod query_builder {
 
             match self.fields {
                 Some(ref fields) => {
-                    query += join(fields, ", ").as_str();
+                    query += render_fields(fields, self.dialect.as_ref()).as_str();
                 },
                 None => query += "*",
             }
 
             query += " FROM ";
-            query += self.table;
+            query += self.dialect.quote_identifier(self.table).as_str();
 
             if let Some(ref aliases) = self.aliases {
                 if let Some(ref alias) = aliases.get(self.table) {
                     query += " AS ";
-                    query += *alias;
+                    query += self.dialect.quote_identifier(alias).as_str();
+                }
+            }
+
+            if let Some(ref joins) = self.joins {
+                for join in joins.iter() {
+                    query += " ";
+                    query += join.join_type.as_sql();
+                    query += " ";
+                    query += self.dialect.quote_identifier(join.table).as_str();
+
+                    if let Some(ref aliases) = self.aliases {
+                        if let Some(ref alias) = aliases.get(join.table) {
+                            query += " AS ";
+                            query += self.dialect.quote_identifier(alias).as_str();
+                        }
+                    }
+
+                    query += " ON ";
+                    query += join.on_left;
+                    query += " = ";
+                    query += join.on_right;
                 }
             }
 
             if let Some(ref conditions) = self.conditions {
                 query += " WHERE ";
-                query += join(conditions, " AND ").as_str();
+                query += render_conditions(conditions).as_str();
+            }
+
+            if let Some(ref group_by) = self.group_by {
+                query += " GROUP BY ";
+                query += join_strs(group_by, ", ").as_str();
+            }
+
+            if let Some(ref having) = self.having {
+                query += " HAVING ";
+                query += join_strs(having, " AND ").as_str();
             }
 
             if let Some(ref order) = self.order {
@@ -273,14 +808,9 @@ pub mod query_builder {
                 }
             }
 
-            if self.limit != 0 {
-                query += " LIMIT ";
-                query += self.limit.to_string().as_str();
-            }
-
-            if self.offset != 0 {
-                query += ", ";
-                query += self.offset.to_string().as_str();
+            if self.limit.is_some() || self.offset != 0 {
+                query += " ";
+                query += self.dialect.limit_offset(self.limit, self.offset).as_str();
             }
 
             query += ";";
@@ -294,53 +824,145 @@ pub mod query_builder {
             let query_builder = Update {
                 table: table,
                 values: HashMap::new(),
+                typed_values: HashMap::new(),
                 conditions: None,
+                dialect: default_dialect(),
             };
 
             query_builder
         }
 
-        /// Set a field value
+        /// Set the SQL dialect used to render this query
+        pub fn dialect(&mut self, dialect: Box<dyn Dialect>) -> &mut Self {
+            self.dialect = dialect;
+            self
+        }
+
+        /// Set a field value as a raw fragment (e.g. a `$1` placeholder).
+        /// The caller is responsible for quoting any literal it passes.
         pub fn set(&mut self, field: &'a str, value: &'a str) -> &mut Self {
             let _ = self.values.insert(field, value);
             self
         }
 
-        /// Filter result set based on conditions (`WHERE` clause)
+        /// Set a field to a typed literal `Value`, quoted/escaped safely
+        pub fn set_value(&mut self, field: &'a str, value: Value) -> &mut Self {
+            let _ = self.typed_values.insert(field, value);
+            self
+        }
+
+        /// Filter result set based on conditions, AND-ed with any prior
+        /// conditions (`WHERE` clause)
         pub fn filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::And(expr.to_string()));
+            self
+        }
+
+        /// Filter result set based on conditions, OR-ed with any prior
+        /// conditions (`WHERE` clause)
+        pub fn or_filter(&mut self, expr: &'a str) -> &mut Self {
+            self.push_condition(ConditionNode::Or(expr.to_string()));
+            self
+        }
+
+        /// Filter result set to rows whose column is one of `values`
+        /// (`IN` clause), AND-ed with any prior conditions. An empty
+        /// `values` list renders a guaranteed-false predicate so the
+        /// query stays valid. `values` are raw fragments dropped into the
+        /// `IN (...)` list as-is, the same convention as `filter()`/`set()`
+        /// — callers are responsible for quoting/placeholdering them in
+        /// whatever style their driver expects.
+        pub fn filter_in(&mut self, column: &'a str, values: &[&str]) -> &mut Self {
+            let expr = if values.is_empty() {
+                String::from("1 = 0")
+            } else {
+                format!("{} IN ({})", column, values.join(", "))
+            };
+            self.push_condition(ConditionNode::And(expr));
+            self
+        }
+
+        /// Filter result set to rows whose column matches a `LIKE` pattern,
+        /// AND-ed with any prior conditions. Emits an explicit `ESCAPE '\'`
+        /// clause so the escaping in `wildcard`'s pattern holds across
+        /// dialects (SQLite has no default `LIKE` escape character)
+        pub fn like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Filter result set to rows whose column does not match a `LIKE`
+        /// pattern, AND-ed with any prior conditions. Emits an explicit
+        /// `ESCAPE '\'` clause so the escaping in `wildcard`'s pattern
+        /// holds across dialects (SQLite has no default `LIKE` escape
+        /// character)
+        pub fn not_like(&mut self, column: &'a str, term: &str, wildcard: LikeWildcard) -> &mut Self {
+            let pattern = like_pattern(term, &wildcard).replace("'", "''");
+            self.push_condition(ConditionNode::And(format!("{} NOT LIKE '{}' ESCAPE '\\'", column, pattern)));
+            self
+        }
+
+        /// Open a parenthetical group of conditions
+        pub fn open_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupStart);
+            self
+        }
+
+        /// Close a parenthetical group of conditions
+        pub fn close_group(&mut self) -> &mut Self {
+            self.push_condition(ConditionNode::GroupEnd);
+            self
+        }
+
+        /// Add a parenthetically grouped set of conditions, built by `f`
+        pub fn filter_group<F>(&mut self, f: F) -> &mut Self
+            where F: FnOnce(&mut Self) -> &mut Self
+        {
+            self.open_group();
+            f(self);
+            self.close_group();
+            self
+        }
+
+        fn push_condition(&mut self, node: ConditionNode) {
             if self.conditions.is_none() {
                 self.conditions = Some(Vec::new());
             }
 
             match self.conditions {
                 Some(ref mut current_conditions) => {
-                    current_conditions.push(expr);
+                    current_conditions.push(node);
                 },
                 None => unreachable!(),
             }
-
-            self
         }
 
         /// Generate SQL query (`String`) from subsequent method calls
         pub fn build(&self) -> String {
             let mut query = String::from("UPDATE ");
-            query += self.table;
+            query += self.dialect.quote_identifier(self.table).as_str();
 
-            let assignments: Vec<String>;
-            assignments = self.values.iter().map(|(&field, &value)| {
-                let mut assignment = String::from(field);
+            let mut assignments: Vec<String> = self.values.iter().map(|(&field, &value)| {
+                let mut assignment = self.dialect.quote_identifier(field);
                 assignment += " = ";
                 assignment += value;
                 assignment
             }).collect();
 
+            for (field, value) in self.typed_values.iter() {
+                let mut assignment = self.dialect.quote_identifier(field);
+                assignment += " = ";
+                assignment += value.render(self.dialect.as_ref()).as_str();
+                assignments.push(assignment);
+            }
+
             query += " SET ";
-            query += assignments.join(" AND ").as_str();
+            query += assignments.join(", ").as_str();
 
             if let Some(ref conditions) = self.conditions {
                 query += " WHERE ";
-                query += join(conditions, " AND ").as_str();
+                query += render_conditions(conditions).as_str();
             }
 
             query += ";";
@@ -400,6 +1022,20 @@ mod tests {
         assert!(possibility1 || possibility2);
     }
 
+    #[test]
+    fn test_insert_query_with_typed_values() {
+        let query = query_builder::insert("users")
+            .set_value("name", query_builder::Value::Varchar(String::from("O'Brien")))
+            .set_value("karma", query_builder::Value::Int(0))
+            .set_value("verified", query_builder::Value::Bool(true))
+            .set_value("referrer", query_builder::Value::Null)
+            .build();
+        assert!(query.contains("'O''Brien'"));
+        assert!(query.contains("karma"));
+        assert!(query.contains("TRUE"));
+        assert!(query.contains("NULL"));
+    }
+
     #[test]
     fn test_select_query() {
         let query = query_builder::select("users")
@@ -423,7 +1059,7 @@ mod tests {
             .build();
         assert_eq!("SELECT id, name FROM users AS u;", query);
     }
- 
+
     #[test]
     fn test_select_query_with_limit() {
         let query = query_builder::select("users")
@@ -432,7 +1068,7 @@ mod tests {
             .build();
         assert_eq!("SELECT id, name FROM users LIMIT 15;", query);
     }
- 
+
     #[test]
     fn test_select_query_with_offset() {
         let query = query_builder::select("users")
@@ -440,9 +1076,141 @@ mod tests {
             .limit(15)
             .offset(30)
             .build();
-        assert_eq!("SELECT id, name FROM users LIMIT 15, 30;", query);
+        assert_eq!("SELECT id, name FROM users LIMIT 15 OFFSET 30;", query);
     }
- 
+
+    #[test]
+    fn test_select_query_with_mysql_dialect_offset() {
+        let query = query_builder::select("users")
+            .fields(&["id", "name"])
+            .dialect(Box::new(query_builder::MySql))
+            .limit(15)
+            .offset(30)
+            .build();
+        assert_eq!("SELECT `id`, `name` FROM `users` LIMIT 30, 15;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_offset_only_generic() {
+        let query = query_builder::select("users")
+            .offset(30)
+            .build();
+        assert_eq!("SELECT * FROM users OFFSET 30;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_offset_only_postgres() {
+        let query = query_builder::select("users")
+            .dialect(Box::new(query_builder::Postgres))
+            .offset(30)
+            .build();
+        assert_eq!("SELECT * FROM \"users\" OFFSET 30;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_offset_only_sqlite() {
+        let query = query_builder::select("users")
+            .dialect(Box::new(query_builder::Sqlite))
+            .offset(30)
+            .build();
+        assert_eq!("SELECT * FROM \"users\" OFFSET 30;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_offset_only_mysql() {
+        let query = query_builder::select("users")
+            .dialect(Box::new(query_builder::MySql))
+            .offset(30)
+            .build();
+        assert_eq!(format!("SELECT * FROM `users` LIMIT 30, {};", u64::MAX), query);
+    }
+
+    #[test]
+    fn test_select_query_with_postgres_dialect_quoting() {
+        let query = query_builder::select("Users")
+            .dialect(Box::new(query_builder::Postgres))
+            .build();
+        assert_eq!("SELECT * FROM \"Users\";", query);
+    }
+
+    #[test]
+    fn test_select_query_with_inner_join() {
+        let query = query_builder::select("users")
+            .fields(&["id", "name"])
+            .inner_join("orders", "users.id", "orders.user_id")
+            .build();
+        assert_eq!("SELECT id, name FROM users INNER JOIN orders ON users.id = orders.user_id;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_left_join_and_alias() {
+        let query = query_builder::select("users")
+            .alias("orders", "o")
+            .fields(&["id", "name"])
+            .left_join("orders", "users.id", "orders.user_id")
+            .build();
+        assert_eq!("SELECT id, name FROM users LEFT JOIN orders AS o ON users.id = orders.user_id;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_multiple_joins() {
+        let query = query_builder::select("users")
+            .join(query_builder::JoinType::Inner, "orders", "users.id", "orders.user_id")
+            .join(query_builder::JoinType::Left, "payments", "orders.id", "payments.order_id")
+            .build();
+        assert_eq!("SELECT * FROM users INNER JOIN orders ON users.id = orders.user_id LEFT JOIN payments ON orders.id = payments.order_id;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_or_filter() {
+        let query = query_builder::select("users")
+            .filter("id = $1")
+            .or_filter("name = $2")
+            .build();
+        assert_eq!("SELECT * FROM users WHERE id = $1 OR name = $2;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_filter_in() {
+        let query = query_builder::select("users")
+            .filter_in("id", &["$1", "$2", "$3"])
+            .build();
+        assert_eq!("SELECT * FROM users WHERE id IN ($1, $2, $3);", query);
+    }
+
+    #[test]
+    fn test_select_query_with_filter_in_empty() {
+        let query = query_builder::select("users")
+            .filter_in("id", &[])
+            .build();
+        assert_eq!("SELECT * FROM users WHERE 1 = 0;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_like() {
+        let query = query_builder::select("users")
+            .like("name", "jacob", query_builder::LikeWildcard::Both)
+            .build();
+        assert_eq!("SELECT * FROM users WHERE name LIKE '%jacob%' ESCAPE '\\';", query);
+    }
+
+    #[test]
+    fn test_select_query_with_not_like_and_escaping() {
+        let query = query_builder::select("users")
+            .not_like("name", "100%_off", query_builder::LikeWildcard::After)
+            .build();
+        assert_eq!("SELECT * FROM users WHERE name NOT LIKE '100\\%\\_off%' ESCAPE '\\';", query);
+    }
+
+    #[test]
+    fn test_select_query_with_filter_group() {
+        let query = query_builder::select("users")
+            .filter_group(|q| q.filter("a = $1").or_filter("b = $2"))
+            .filter("c = $3")
+            .build();
+        assert_eq!("SELECT * FROM users WHERE (a = $1 OR b = $2) AND c = $3;", query);
+    }
+
     #[test]
     fn test_select_query_with_conditions() {
         let query = query_builder::select("users")
@@ -452,7 +1220,7 @@ mod tests {
             .build();
         assert_eq!("SELECT id, name FROM users WHERE id = $1 AND name = $2;", query);
     }
- 
+
     #[test]
     fn test_select_query_with_order() {
         let query = query_builder::select("users")
@@ -463,14 +1231,33 @@ mod tests {
         assert_eq!("SELECT id, name FROM users WHERE name = $1 ORDER BY id ASC;", query);
     }
 
+    #[test]
+    fn test_select_query_with_group_by_and_having() {
+        let query = query_builder::select("orders")
+            .fields(&["customer_id", "COUNT(*) AS n"])
+            .group_by(&["customer_id"])
+            .having("COUNT(*) > 1")
+            .build();
+        assert_eq!("SELECT customer_id, COUNT(*) AS n FROM orders GROUP BY customer_id HAVING COUNT(*) > 1;", query);
+    }
+
+    #[test]
+    fn test_select_query_with_reserved_word_fields_mysql_dialect() {
+        let query = query_builder::select("users")
+            .dialect(Box::new(query_builder::MySql))
+            .fields(&["order", "group", "COUNT(*) AS n"])
+            .build();
+        assert_eq!("SELECT `order`, `group`, COUNT(*) AS n FROM `users`;", query);
+    }
+
     #[test]
     fn test_update_query() {
         let query = query_builder::update("users")
             .set("karma", "0")
             .set("last_login", "1970-01-01")
             .build();
-        let possibility1 = "UPDATE users SET karma = 0 AND last_login = 1970-01-01;" == query;
-        let possibility2 = "UPDATE users SET last_login = 1970-01-01 AND karma = 0;" == query;
+        let possibility1 = "UPDATE users SET karma = 0, last_login = 1970-01-01;" == query;
+        let possibility2 = "UPDATE users SET last_login = 1970-01-01, karma = 0;" == query;
         assert!(possibility1 || possibility2);
     }
 
@@ -483,4 +1270,12 @@ mod tests {
             .build();
         assert_eq!("UPDATE users SET karma = 0 WHERE name = $1 AND last_login < $2;", query);
     }
+
+    #[test]
+    fn test_update_query_with_typed_value() {
+        let query = query_builder::update("users")
+            .set_value("name", query_builder::Value::Varchar(String::from("O'Brien")))
+            .build();
+        assert_eq!("UPDATE users SET name = 'O''Brien';", query);
+    }
 }